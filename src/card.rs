@@ -0,0 +1,402 @@
+use core::fmt;
+use std::fmt::Display;
+use std::str::FromStr;
+
+use serde::{Deserialize, Serialize};
+
+/// Returned when a card, suit, or value can't be parsed from a short string
+/// like `"AS"` or `"10H"`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseCardError(String);
+
+impl Display for ParseCardError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "invalid card: {:?}", self.0)
+    }
+}
+
+impl std::error::Error for ParseCardError {}
+
+#[derive(Debug, Eq, PartialEq, Copy, Clone, Ord, PartialOrd, Hash, Serialize, Deserialize)]
+pub enum Suit {
+    Spades,
+    Hearts,
+    Diamonds,
+    Clubs,
+}
+
+impl Display for Suit {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if f.alternate() {
+            let symbol = match *self {
+                Suit::Spades => "♠",
+                Suit::Hearts => "♥",
+                Suit::Diamonds => "♦",
+                Suit::Clubs => "♣",
+            };
+            return write!(f, "{}", symbol);
+        }
+
+        let result = match *self {
+            Suit::Spades => "Spades",
+            Suit::Hearts => "Hearts",
+            Suit::Diamonds => "Diamonds",
+            Suit::Clubs => "Clubs",
+        }
+        .to_string();
+        write!(f, "{}", result)
+    }
+}
+
+impl FromStr for Suit {
+    type Err = ParseCardError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "S" | "♠" => Ok(Suit::Spades),
+            "H" | "♥" => Ok(Suit::Hearts),
+            "D" | "♦" => Ok(Suit::Diamonds),
+            "C" | "♣" => Ok(Suit::Clubs),
+            _ => Err(ParseCardError(s.to_string())),
+        }
+    }
+}
+
+#[derive(Debug, Eq, PartialEq, Copy, Clone, Ord, PartialOrd, Hash, Serialize, Deserialize)]
+pub enum Value {
+    Two,
+    Three,
+    Four,
+    Five,
+    Six,
+    Seven,
+    Eight,
+    Nine,
+    Ten,
+    Jack,
+    Queen,
+    King,
+    Ace,
+}
+
+impl Display for Value {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let result = match *self {
+            Value::Two => "2",
+            Value::Three => "3",
+            Value::Four => "4",
+            Value::Five => "5",
+            Value::Six => "6",
+            Value::Seven => "7",
+            Value::Eight => "8",
+            Value::Nine => "9",
+            Value::Ten => "10",
+            Value::Jack => "J",
+            Value::Queen => "Q",
+            Value::King => "K",
+            Value::Ace => "A",
+        }
+        .to_string();
+        write!(f, "{}", result)
+    }
+}
+
+impl FromStr for Value {
+    type Err = ParseCardError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "2" => Ok(Value::Two),
+            "3" => Ok(Value::Three),
+            "4" => Ok(Value::Four),
+            "5" => Ok(Value::Five),
+            "6" => Ok(Value::Six),
+            "7" => Ok(Value::Seven),
+            "8" => Ok(Value::Eight),
+            "9" => Ok(Value::Nine),
+            "10" => Ok(Value::Ten),
+            "J" => Ok(Value::Jack),
+            "Q" => Ok(Value::Queen),
+            "K" => Ok(Value::King),
+            "A" => Ok(Value::Ace),
+            _ => Err(ParseCardError(s.to_string())),
+        }
+    }
+}
+
+impl From<Value> for usize {
+    fn from(value: Value) -> Self {
+        match value {
+            Value::Two => 2,
+            Value::Three => 3,
+            Value::Four => 4,
+            Value::Five => 5,
+            Value::Six => 6,
+            Value::Seven => 7,
+            Value::Eight => 8,
+            Value::Nine => 9,
+            Value::Ten | Value::Jack | Value::Queen | Value::King => 10,
+            Value::Ace => 11,
+        }
+    }
+}
+
+pub const SUITS: [Suit; 4] = [Suit::Spades, Suit::Hearts, Suit::Diamonds, Suit::Clubs];
+pub const VALUES: [Value; 13] = [
+    Value::Two,
+    Value::Three,
+    Value::Four,
+    Value::Five,
+    Value::Six,
+    Value::Seven,
+    Value::Eight,
+    Value::Nine,
+    Value::Ten,
+    Value::Jack,
+    Value::Queen,
+    Value::King,
+    Value::Ace,
+];
+
+#[derive(Debug, Eq, PartialEq, Copy, Clone, Ord, PartialOrd, Hash, Serialize, Deserialize)]
+pub struct Card {
+    pub value: Value,
+    pub suit: Suit,
+}
+
+impl Card {
+    pub fn new(value: Value, suit: Suit) -> Self {
+        Self { value, suit }
+    }
+
+    /// Compact Unicode rendering, e.g. `"A♠"` instead of `"Ace of Spades"`.
+    pub fn symbol(&self) -> String {
+        format!("{:#}", self)
+    }
+}
+
+impl fmt::Display for Card {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        if f.alternate() {
+            return write!(f, "{:#}{:#}", self.value, self.suit);
+        }
+        write!(f, "{:?} of {:?}", &self.value, &self.suit,)
+    }
+}
+
+impl FromStr for Card {
+    type Err = ParseCardError;
+
+    /// Parses short forms like `"AS"`, `"10H"`, or `"KC"`: a `Value` followed
+    /// by a single-character `Suit`, either a letter (`S`, `H`, `D`, `C`) or a
+    /// suit symbol (`♠`, `♥`, `♦`, `♣`) as rendered by `Card::symbol()`.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        // Split on the last *character*, not the last byte: the suit can be
+        // a multi-byte Unicode symbol (e.g. from `Card::symbol()`), and
+        // slicing on a byte offset that isn't a char boundary panics.
+        let last_char_start = s
+            .char_indices()
+            .last()
+            .map(|(index, _)| index)
+            .ok_or_else(|| ParseCardError(s.to_string()))?;
+
+        if last_char_start == 0 {
+            return Err(ParseCardError(s.to_string()));
+        }
+
+        let (value, suit) = s.split_at(last_char_start);
+        Ok(Card::new(value.parse()?, suit.parse()?))
+    }
+}
+
+pub fn create_deck() -> Vec<Card> {
+    let mut cards = Vec::new();
+
+    for suit in SUITS {
+        for value in VALUES {
+            cards.push(Card::new(value, suit));
+        }
+    }
+
+    cards
+}
+
+/// The best total for a hand, along with whether it is "soft" (still
+/// counting an Ace as 11).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct HandValue {
+    pub total: usize,
+    pub soft: bool,
+}
+
+/// Scores a hand, counting every Ace as 11 first and then downgrading Aces
+/// to 1 one at a time while the total is over 21. A+6 scores 17 "soft"
+/// (the Ace still counts as 11); A+6+K scores 17 "hard" (the Ace had to be
+/// downgraded to keep the total at or under 21).
+pub fn hand_value(cards: &[Card]) -> HandValue {
+    let aces = cards.iter().filter(|card| card.value == Value::Ace).count();
+    let mut total: usize = cards.iter().map(|card| usize::from(card.value)).sum();
+
+    let mut downgraded = 0;
+    while total > 21 && downgraded < aces {
+        total -= 10;
+        downgraded += 1;
+    }
+
+    HandValue {
+        total,
+        soft: downgraded < aces,
+    }
+}
+
+/// A natural 21 on the first two cards dealt.
+pub fn is_blackjack(cards: &[Card]) -> bool {
+    cards.len() == 2 && hand_value(cards).total == 21
+}
+
+pub const MAX_VALUE: usize = 21;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum Outcome {
+    Win,
+    Loss,
+    Push,
+    Blackjack,
+}
+
+/// Resolves a hand against the dealer's hand, treating bust, push, and
+/// blackjack as distinct outcomes rather than a raw total comparison. Shared
+/// by every engine in the crate so they can't drift on the house rules.
+pub fn resolve_outcome(hand: &[Card], dealer_hand: &[Card]) -> Outcome {
+    let blackjack = is_blackjack(hand);
+    let dealer_blackjack = is_blackjack(dealer_hand);
+
+    match (blackjack, dealer_blackjack) {
+        (true, true) => Outcome::Push,
+        (true, false) => Outcome::Blackjack,
+        (false, true) => Outcome::Loss,
+        (false, false) => {
+            let total = hand_value(hand).total;
+            let dealer_total = hand_value(dealer_hand).total;
+
+            if total > MAX_VALUE {
+                Outcome::Loss
+            } else if dealer_total > MAX_VALUE {
+                Outcome::Win
+            } else if total == dealer_total {
+                Outcome::Push
+            } else if total > dealer_total {
+                Outcome::Win
+            } else {
+                Outcome::Loss
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn ace_six_is_soft_seventeen() {
+        let hand = [Card::new(Value::Ace, Suit::Spades), Card::new(Value::Six, Suit::Hearts)];
+        let value = hand_value(&hand);
+        assert_eq!(value.total, 17);
+        assert!(value.soft);
+    }
+
+    #[test]
+    fn ace_six_king_is_hard_seventeen() {
+        let hand = [
+            Card::new(Value::Ace, Suit::Spades),
+            Card::new(Value::Six, Suit::Hearts),
+            Card::new(Value::King, Suit::Clubs),
+        ];
+        let value = hand_value(&hand);
+        assert_eq!(value.total, 17);
+        assert!(!value.soft);
+    }
+
+    #[test]
+    fn two_aces_count_as_twelve() {
+        let hand = [Card::new(Value::Ace, Suit::Spades), Card::new(Value::Ace, Suit::Hearts)];
+        let value = hand_value(&hand);
+        assert_eq!(value.total, 12);
+        assert!(value.soft);
+    }
+
+    #[test]
+    fn every_card_round_trips_through_its_symbol() {
+        for suit in SUITS {
+            for value in VALUES {
+                let card = Card::new(value, suit);
+                assert_eq!(card.symbol().parse::<Card>().unwrap(), card);
+            }
+        }
+    }
+
+    #[test]
+    fn card_from_str_parses_short_forms() {
+        assert_eq!(
+            "AS".parse::<Card>().unwrap(),
+            Card::new(Value::Ace, Suit::Spades)
+        );
+        assert_eq!(
+            "10H".parse::<Card>().unwrap(),
+            Card::new(Value::Ten, Suit::Hearts)
+        );
+        assert_eq!(
+            "KC".parse::<Card>().unwrap(),
+            Card::new(Value::King, Suit::Clubs)
+        );
+    }
+
+    #[test]
+    fn card_symbol_round_trips_through_from_str() {
+        let card = Card::new(Value::Ace, Suit::Spades);
+        assert_eq!(card.symbol(), "A♠");
+        assert_eq!(card.symbol().parse::<Card>().unwrap(), card);
+    }
+
+    #[test]
+    fn card_from_str_rejects_garbage_without_panicking() {
+        assert!("".parse::<Card>().is_err());
+        assert!("A".parse::<Card>().is_err());
+        assert!("♠".parse::<Card>().is_err());
+        assert!("ZZ".parse::<Card>().is_err());
+    }
+
+    /// A scripted round built from deterministic hands (rather than a
+    /// shuffled deck) so the outcome is fully predictable.
+    #[test]
+    fn scripted_round_resolves_a_player_win() {
+        let dealer_cards = [Card::new(Value::Nine, Suit::Hearts), Card::new(Value::Seven, Suit::Diamonds)];
+        let player_cards = [Card::new(Value::Nine, Suit::Spades), Card::new(Value::Eight, Suit::Clubs)];
+
+        assert_eq!(hand_value(&player_cards).total, 17);
+        assert_eq!(hand_value(&dealer_cards).total, 16);
+        assert_eq!(resolve_outcome(&player_cards, &dealer_cards), Outcome::Win);
+    }
+
+    #[test]
+    fn scripted_round_resolves_a_bust() {
+        let dealer_cards = [Card::new(Value::Nine, Suit::Hearts), Card::new(Value::Seven, Suit::Diamonds)];
+        let player_cards = [
+            Card::new(Value::Nine, Suit::Spades),
+            Card::new(Value::Eight, Suit::Clubs),
+            Card::new(Value::King, Suit::Clubs),
+        ];
+
+        assert_eq!(hand_value(&player_cards).total, 27);
+        assert_eq!(resolve_outcome(&player_cards, &dealer_cards), Outcome::Loss);
+    }
+
+    #[test]
+    fn natural_blackjack_beats_a_non_blackjack_dealer() {
+        let dealer_cards = [Card::new(Value::King, Suit::Hearts), Card::new(Value::Nine, Suit::Diamonds)];
+        let player_cards = [Card::new(Value::Ace, Suit::Spades), Card::new(Value::King, Suit::Clubs)];
+
+        assert_eq!(resolve_outcome(&player_cards, &dealer_cards), Outcome::Blackjack);
+    }
+}
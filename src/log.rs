@@ -0,0 +1,83 @@
+use serde::{Deserialize, Serialize};
+
+use crate::card::{Card, Outcome};
+
+/// One step of a round: the initial deal, a hit, a stand, a dealer draw, or
+/// a seat's final outcome. Recording these lets a round be replayed, diffed,
+/// or fed to an analyzer instead of only played live.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(tag = "event")]
+pub enum RoundEvent {
+    InitialDeal {
+        dealer_cards: Vec<Card>,
+        seats: Vec<(String, Vec<Card>)>,
+    },
+    Hit {
+        seat: String,
+        card: Card,
+    },
+    Stand {
+        seat: String,
+    },
+    DealerDraw {
+        card: Card,
+    },
+    Outcome {
+        seat: String,
+        outcome: Outcome,
+    },
+}
+
+/// A structured, serializable transcript of a single round.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct RoundLog {
+    pub events: Vec<RoundEvent>,
+}
+
+impl RoundLog {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn record(&mut self, event: RoundEvent) {
+        self.events.push(event);
+    }
+
+    pub fn to_json(&self) -> serde_json::Result<String> {
+        serde_json::to_string_pretty(self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rand::rngs::StdRng;
+    use rand::SeedableRng;
+
+    use super::*;
+    use crate::strategy::BasicStrategy;
+    use crate::table::{Seat, Table};
+
+    #[test]
+    fn round_log_serializes_a_full_transcript_and_round_trips() {
+        let seats = vec![
+            Seat::new("You", Box::new(BasicStrategy)),
+            Seat::new("Bot", Box::new(BasicStrategy)),
+        ];
+        let mut rng = StdRng::seed_from_u64(1);
+        let mut table = Table::deal_shoe(seats, 1, &mut rng).expect("shoe should have enough cards");
+        let (results, log) = table.play_round();
+
+        assert!(matches!(log.events.first(), Some(RoundEvent::InitialDeal { .. })));
+        assert_eq!(
+            log.events
+                .iter()
+                .filter(|event| matches!(event, RoundEvent::Outcome { .. }))
+                .count(),
+            results.len()
+        );
+
+        let json = log.to_json().expect("round log should serialize");
+        let reloaded: RoundLog = serde_json::from_str(&json).expect("round log should deserialize");
+        assert_eq!(reloaded.events.len(), log.events.len());
+    }
+}
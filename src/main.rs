@@ -1,240 +1,84 @@
-use core::fmt;
-use std::{
-    error::Error,
-    fmt::Display,
-    io::{self, BufRead},
-};
-
-use rand::seq::SliceRandom;
-
-#[derive(Debug, Eq, PartialEq, Copy, Clone, Ord, PartialOrd, Hash)]
-pub enum Suit {
-    Spades,
-    Hearts,
-    Diamonds,
-    Clubs,
-}
+use std::{error::Error, io};
 
-impl Display for Suit {
-    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        let result = match *self {
-            Suit::Spades => "Spades",
-            Suit::Hearts => "Hearts",
-            Suit::Diamonds => "Diamonds",
-            Suit::Clubs => "Clubs",
-        }
-        .to_string();
-        write!(f, "{}", result)
-    }
-}
+mod card;
+mod log;
+mod sim;
+mod strategy;
+mod table;
 
-#[derive(Debug, Eq, PartialEq, Copy, Clone, Ord, PartialOrd, Hash)]
-pub enum Value {
-    Two,
-    Three,
-    Four,
-    Five,
-    Six,
-    Seven,
-    Eight,
-    Nine,
-    Ten,
-    Jack,
-    Queen,
-    King,
-    Ace,
-}
+use card::Outcome;
+use strategy::{BasicStrategy, HumanStdin};
+use table::{Seat, Table};
 
-impl Display for Value {
-    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        let result = match *self {
-            Value::Two => "2",
-            Value::Three => "3",
-            Value::Four => "4",
-            Value::Five => "5",
-            Value::Six => "6",
-            Value::Seven => "7",
-            Value::Eight => "8",
-            Value::Nine => "9",
-            Value::Ten => "10",
-            Value::Jack => "J",
-            Value::Queen => "Q",
-            Value::King => "K",
-            Value::Ace => "A",
-        }
-        .to_string();
-        write!(f, "{}", result)
-    }
+fn arg_value(args: &[String], flag: &str) -> Option<String> {
+    let position = args.iter().position(|arg| arg == flag)?;
+    args.get(position + 1).cloned()
 }
 
-impl From<Value> for usize {
-    fn from(value: Value) -> Self {
-        match value {
-            Value::Two => 2,
-            Value::Three => 3,
-            Value::Four => 4,
-            Value::Five => 5,
-            Value::Six => 6,
-            Value::Seven => 7,
-            Value::Eight => 8,
-            Value::Nine => 9,
-            Value::Ten | Value::Jack | Value::Queen | Value::King => 10,
-            Value::Ace => 11,
-        }
-    }
+fn clear_screen() {
+    print!("{}[2J", 27 as char);
 }
 
-const SUITS: [Suit; 4] = [Suit::Spades, Suit::Hearts, Suit::Diamonds, Suit::Clubs];
-const VALUES: [Value; 13] = [
-    Value::Two,
-    Value::Three,
-    Value::Four,
-    Value::Five,
-    Value::Six,
-    Value::Seven,
-    Value::Eight,
-    Value::Nine,
-    Value::Ten,
-    Value::Jack,
-    Value::Queen,
-    Value::King,
-    Value::Ace,
-];
-
-#[derive(Debug, Eq, PartialEq, Copy, Clone, Ord, PartialOrd, Hash)]
-pub struct Card {
-    pub value: Value,
-    pub suit: Suit,
-}
+fn play_round(json: bool) -> Result<(), Box<dyn Error>> {
+    let seats = vec![
+        Seat::new("You", Box::new(HumanStdin)),
+        Seat::new("Basic Bot", Box::new(BasicStrategy)),
+    ];
 
-impl Card {
-    pub fn new(value: Value, suit: Suit) -> Self {
-        Self { value, suit }
-    }
-}
+    let mut table = Table::deal(seats)?;
+    let (results, round_log) = table.play_round();
 
-impl fmt::Display for Card {
-    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        write!(f, "{:?} of {:?}", &self.value, &self.suit,)
+    if json {
+        println!("{}", round_log.to_json()?);
+        return Ok(());
     }
-}
 
-fn create_deck() -> Vec<Card> {
-    let mut cards = Vec::new();
+    clear_screen();
+    println!(
+        "Dealer cards: {}",
+        table
+            .dealer_cards
+            .iter()
+            .map(|card| card.symbol())
+            .collect::<Vec<_>>()
+            .join(", ")
+    );
 
-    for suit in SUITS {
-        for value in VALUES {
-            cards.push(Card::new(value, suit));
+    for (name, outcome) in results {
+        match outcome {
+            Outcome::Blackjack => println!("{} got a blackjack!", name),
+            Outcome::Win => println!("{} won!", name),
+            Outcome::Push => println!("{} pushed.", name),
+            Outcome::Loss => println!("{} lost!", name),
         }
     }
 
-    cards
-}
-
-fn card_total(cards: &[Card]) -> usize {
-    cards
-        .iter()
-        .fold(0, |total, card| total + usize::from(card.value))
-}
-
-fn print_dealer_cards(cards: &[Card]) {
-    println!("Dealer cards:");
-    let mut first_hidden = false;
-    cards.iter().for_each(|card| match first_hidden {
-        true => println!("* {}", card),
-        false => {
-            println!("* ??");
-            first_hidden = true;
-        }
-    });
-    println!("")
+    Ok(())
 }
 
-fn print_player_cards(cards: &[Card]) {
-    println!("Your cards:");
-    for card in cards.iter() {
-        println!("* {}", card);
+fn main() -> Result<(), Box<dyn Error>> {
+    let args: Vec<String> = std::env::args().collect();
+
+    if let Some(rounds) = arg_value(&args, "--simulate") {
+        let rounds: usize = rounds.parse()?;
+        let seed: u64 = arg_value(&args, "--seed")
+            .map(|value| value.parse())
+            .transpose()?
+            .unwrap_or(42);
+        let decks: usize = arg_value(&args, "--decks")
+            .map(|value| value.parse())
+            .transpose()?
+            .unwrap_or(1);
+
+        let stats = sim::simulate(|| Box::new(BasicStrategy), rounds, seed, decks);
+        println!("{}", stats.summary());
+        return Ok(());
     }
-    println!("* Total: {}", card_total(&cards));
-    println!("")
-}
 
-fn print_player_options() {
-    println!(
-        r#"
-Options
-1.) Hit
-2.) Stay
-    "#
-    );
-}
-
-fn clear_screen() {
-    print!("{}[2J", 27 as char);
-}
-
-const MAX_VALUE: usize = 21;
-
-fn play_round() -> Result<bool, Box<dyn Error>> {
-    let mut deck = create_deck();
-    deck.shuffle(&mut rand::thread_rng());
-
-    let mut dealer_cards = Vec::new();
-    dealer_cards.extend_from_slice(deck.split_off(deck.len() - 2).as_slice());
-
-    let mut player_cards = Vec::new();
-    player_cards.extend_from_slice(deck.split_off(deck.len() - 2).as_slice());
-
-    clear_screen();
-    print_dealer_cards(&dealer_cards);
-    print_player_cards(&player_cards);
-    print_player_options();
-
-    let mut lines = io::stdin().lock().lines();
-    while let Some(Ok(line)) = lines.next() {
-        clear_screen();
-        print_dealer_cards(&dealer_cards);
-        print_player_cards(&player_cards);
-        print_player_options();
-
-        match line.as_str() {
-            "1" => {
-                // Hit
-                player_cards.extend_from_slice(deck.split_off(deck.len() - 1).as_slice());
-                if card_total(&player_cards) > MAX_VALUE {
-                    print_player_cards(&player_cards);
-
-                    println!("You went over {}! Game over.", MAX_VALUE);
-                    println!("Thanks for playing!");
-                    return Ok(false);
-                }
-            }
-            "2" => {
-                // Stand
-                let player_total = card_total(&player_cards);
-                let dealer_total = card_total(&dealer_cards);
-                if player_total < dealer_total {
-                    println!(
-                        "You lost! [Dealer score ({}) > Player score ({})]",
-                        dealer_total, player_total
-                    );
-                    return Ok(false);
-                } else {
-                    println!(
-                        "You won! [Dealer score: ({}) < Player score: ({})]",
-                        dealer_total, player_total
-                    );
-                    return Ok(true);
-                }
-            }
-            _ => println!("Invalid option. Please select either 'Hit' or 'Stay'."),
-        }
+    if args.iter().any(|arg| arg == "--json") {
+        return play_round(true);
     }
 
-    Ok(false)
-}
-
-fn main() -> Result<(), Box<dyn Error>> {
     println!("--- Welcome to Matt's Blackjack table! ---");
     println!("Press any key to start playing.");
     io::stdin().lines().next().unwrap()?;
@@ -242,7 +86,7 @@ fn main() -> Result<(), Box<dyn Error>> {
     clear_screen();
     println!("--- Matt's Blackjack table ---");
 
-    play_round()?;
+    play_round(false)?;
 
     Ok(())
 }
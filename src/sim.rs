@@ -0,0 +1,85 @@
+use rand::rngs::StdRng;
+use rand::SeedableRng;
+
+use crate::card::Outcome;
+use crate::strategy::Strategy;
+use crate::table::{Seat, Table};
+
+/// Win/loss/push tallies and expected value for a run of simulated rounds.
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct SimulationStats {
+    pub rounds: usize,
+    pub wins: usize,
+    pub losses: usize,
+    pub pushes: usize,
+    pub blackjacks: usize,
+}
+
+impl SimulationStats {
+    /// Expected value per hand assuming a flat 1-unit bet and a 3:2
+    /// blackjack payout.
+    pub fn expected_value(&self) -> f64 {
+        if self.rounds == 0 {
+            return 0.0;
+        }
+
+        let units = self.wins as f64 + self.blackjacks as f64 * 1.5 - self.losses as f64;
+        units / self.rounds as f64
+    }
+
+    pub fn summary(&self) -> String {
+        format!(
+            "{} rounds -> {} wins, {} losses, {} pushes, {} blackjacks (EV: {:.4} per hand)",
+            self.rounds,
+            self.wins,
+            self.losses,
+            self.pushes,
+            self.blackjacks,
+            self.expected_value()
+        )
+    }
+}
+
+/// Plays `rounds` solo rounds of blackjack with a fresh `Strategy` from
+/// `new_strategy`, using a seeded RNG so the run is reproducible, and tallies
+/// the outcomes.
+pub fn simulate(
+    new_strategy: impl Fn() -> Box<dyn Strategy>,
+    rounds: usize,
+    seed: u64,
+    decks: usize,
+) -> SimulationStats {
+    let mut rng = StdRng::seed_from_u64(seed);
+    let mut stats = SimulationStats::default();
+
+    for _ in 0..rounds {
+        let seats = vec![Seat::new("Trainee", new_strategy())];
+        let mut table = Table::deal_shoe(seats, decks, &mut rng)
+            .expect("a single-seat shoe should always have enough cards");
+        let (results, _log) = table.play_round();
+
+        stats.rounds += 1;
+        match results[0].1 {
+            Outcome::Win => stats.wins += 1,
+            Outcome::Loss => stats.losses += 1,
+            Outcome::Push => stats.pushes += 1,
+            Outcome::Blackjack => stats.blackjacks += 1,
+        }
+    }
+
+    stats
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::strategy::BasicStrategy;
+
+    #[test]
+    fn simulate_is_deterministic_for_a_repeated_seed() {
+        let first = simulate(|| Box::new(BasicStrategy), 200, 7, 1);
+        let second = simulate(|| Box::new(BasicStrategy), 200, 7, 1);
+
+        assert_eq!(first, second);
+    }
+}
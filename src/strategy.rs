@@ -0,0 +1,114 @@
+use std::io::{self, BufRead};
+
+use crate::card::{hand_value, Card};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Action {
+    Hit,
+    Stand,
+}
+
+/// Decides whether a seat hits or stands given its own cards and the dealer's
+/// visible upcard. Implementations range from a human prompt to simple bots.
+pub trait Strategy {
+    fn decide(&self, own: &[Card], dealer_upcard: Card) -> Action;
+}
+
+/// Prompts on stdin, mirroring the terminal driver's original hit/stay prompt.
+pub struct HumanStdin;
+
+impl Strategy for HumanStdin {
+    fn decide(&self, own: &[Card], dealer_upcard: Card) -> Action {
+        loop {
+            println!("Dealer shows: {}", dealer_upcard);
+            println!("Your cards:");
+            for card in own {
+                println!("* {}", card);
+            }
+            println!("* Total: {}", hand_value(own).total);
+            println!(
+                r#"
+Options
+1.) Hit
+2.) Stay
+    "#
+            );
+
+            let mut lines = io::stdin().lock().lines();
+            match lines.next() {
+                Some(Ok(line)) => match line.as_str() {
+                    "1" => return Action::Hit,
+                    "2" => return Action::Stand,
+                    _ => println!("Invalid option. Please select either 'Hit' or 'Stay'."),
+                },
+                _ => return Action::Stand,
+            }
+        }
+    }
+}
+
+/// Draws until reaching 17, same as house rules for the dealer's own hand.
+pub struct DealerStrategy;
+
+impl Strategy for DealerStrategy {
+    fn decide(&self, own: &[Card], _dealer_upcard: Card) -> Action {
+        if hand_value(own).total < 17 {
+            Action::Hit
+        } else {
+            Action::Stand
+        }
+    }
+}
+
+/// A simplified basic-strategy bot: hits hard totals below 12 outright, and
+/// keeps hitting up to 17 against a strong dealer upcard (7 or higher).
+pub struct BasicStrategy;
+
+impl Strategy for BasicStrategy {
+    fn decide(&self, own: &[Card], dealer_upcard: Card) -> Action {
+        let total = hand_value(own).total;
+        let dealer_shows_strong = usize::from(dealer_upcard.value) >= 7;
+
+        if total < 12 || (dealer_shows_strong && total < 17) {
+            Action::Hit
+        } else {
+            Action::Stand
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::card::{Suit, Value};
+
+    #[test]
+    fn dealer_strategy_hits_below_seventeen_and_stands_at_seventeen() {
+        let sixteen = [Card::new(Value::Nine, Suit::Spades), Card::new(Value::Seven, Suit::Hearts)];
+        let seventeen = [Card::new(Value::Ten, Suit::Spades), Card::new(Value::Seven, Suit::Hearts)];
+        let upcard = Card::new(Value::Two, Suit::Clubs);
+
+        assert_eq!(DealerStrategy.decide(&sixteen, upcard), Action::Hit);
+        assert_eq!(DealerStrategy.decide(&seventeen, upcard), Action::Stand);
+    }
+
+    #[test]
+    fn basic_strategy_hits_hard_totals_below_twelve_regardless_of_upcard() {
+        let hand = [Card::new(Value::Five, Suit::Spades), Card::new(Value::Six, Suit::Hearts)];
+        let weak_upcard = Card::new(Value::Four, Suit::Clubs);
+        let strong_upcard = Card::new(Value::Ten, Suit::Clubs);
+
+        assert_eq!(BasicStrategy.decide(&hand, weak_upcard), Action::Hit);
+        assert_eq!(BasicStrategy.decide(&hand, strong_upcard), Action::Hit);
+    }
+
+    #[test]
+    fn basic_strategy_keeps_hitting_to_seventeen_against_a_strong_upcard_but_stands_against_a_weak_one() {
+        let sixteen = [Card::new(Value::Ten, Suit::Spades), Card::new(Value::Six, Suit::Hearts)];
+        let weak_upcard = Card::new(Value::Six, Suit::Clubs);
+        let strong_upcard = Card::new(Value::Nine, Suit::Clubs);
+
+        assert_eq!(BasicStrategy.decide(&sixteen, weak_upcard), Action::Stand);
+        assert_eq!(BasicStrategy.decide(&sixteen, strong_upcard), Action::Hit);
+    }
+}
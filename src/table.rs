@@ -0,0 +1,297 @@
+use core::fmt;
+
+use rand::seq::SliceRandom;
+use rand::Rng;
+
+use crate::card::{create_deck, hand_value, is_blackjack, resolve_outcome, Card, Outcome, MAX_VALUE};
+use crate::log::{RoundEvent, RoundLog};
+use crate::strategy::{Action, DealerStrategy, Strategy};
+
+/// Returned when a shoe doesn't have enough cards left to deal two cards to
+/// the dealer and every seat.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct DealError {
+    pub needed: usize,
+    pub available: usize,
+}
+
+impl fmt::Display for DealError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "not enough cards to deal: needed {}, shoe has {}",
+            self.needed, self.available
+        )
+    }
+}
+
+impl std::error::Error for DealError {}
+
+/// Whether a seat is still taking hits/stands, or has finished its turn
+/// (stood, busted, or was dealt a natural blackjack).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SeatPhase {
+    Turn,
+    Done,
+}
+
+/// A seat at the table: a name, the strategy driving its decisions, its
+/// current hand, and whether it's still taking its turn.
+pub struct Seat {
+    pub name: String,
+    pub strategy: Box<dyn Strategy>,
+    pub cards: Vec<Card>,
+    pub phase: SeatPhase,
+}
+
+impl Seat {
+    pub fn new(name: impl Into<String>, strategy: Box<dyn Strategy>) -> Self {
+        Self {
+            name: name.into(),
+            strategy,
+            cards: Vec::new(),
+            phase: SeatPhase::Turn,
+        }
+    }
+}
+
+/// Deals a shared deck and dealer hand out to every seat. `play_round` drives
+/// every seat to completion via its `Strategy` in one call, but `hit`/`stand`
+/// are also exposed per seat so an external frontend (a GUI, a step-through
+/// debugger) can drive one decision at a time instead of requiring a
+/// synchronous `Strategy` impl.
+pub struct Table {
+    pub deck: Vec<Card>,
+    pub dealer_cards: Vec<Card>,
+    pub seats: Vec<Seat>,
+}
+
+impl Table {
+    pub fn deal(seats: Vec<Seat>) -> Result<Self, DealError> {
+        Self::deal_shoe(seats, 1, &mut rand::thread_rng())
+    }
+
+    /// Deals from a shoe of `decks` stacked copies of `create_deck()` (at
+    /// least one, even if `decks` is 0), shuffled with the given RNG. A seeded RNG makes a simulation run
+    /// reproducible; multi-deck shoes matter because blackjack EV depends on
+    /// deck count. Errors instead of panicking if the shoe doesn't have
+    /// enough cards to deal two to the dealer and every seat.
+    pub fn deal_shoe(mut seats: Vec<Seat>, decks: usize, rng: &mut impl Rng) -> Result<Self, DealError> {
+        let mut deck = Vec::new();
+        for _ in 0..decks.max(1) {
+            deck.extend(create_deck());
+        }
+        deck.shuffle(rng);
+
+        let needed = 2 * (seats.len() + 1);
+        if deck.len() < needed {
+            return Err(DealError {
+                needed,
+                available: deck.len(),
+            });
+        }
+
+        let mut dealer_cards = Vec::new();
+        dealer_cards.extend_from_slice(deck.split_off(deck.len() - 2).as_slice());
+
+        for seat in &mut seats {
+            seat.cards
+                .extend_from_slice(deck.split_off(deck.len() - 2).as_slice());
+
+            if is_blackjack(&seat.cards) {
+                seat.phase = SeatPhase::Done;
+            }
+        }
+
+        Ok(Self {
+            deck,
+            dealer_cards,
+            seats,
+        })
+    }
+
+    pub fn dealer_upcard(&self) -> Card {
+        self.dealer_cards[0]
+    }
+
+    /// Hits the seat at `seat_index` once. Returns the drawn card, or `None`
+    /// if the seat has already finished its turn or the deck is exhausted.
+    /// Busting ends the seat's turn.
+    pub fn hit(&mut self, seat_index: usize) -> Option<Card> {
+        let seat = self.seats.get_mut(seat_index)?;
+        if seat.phase != SeatPhase::Turn {
+            return None;
+        }
+
+        let card = self.deck.pop()?;
+        seat.cards.push(card);
+
+        if hand_value(&seat.cards).total > MAX_VALUE {
+            seat.phase = SeatPhase::Done;
+        }
+
+        Some(card)
+    }
+
+    /// Ends the seat's turn without drawing again.
+    pub fn stand(&mut self, seat_index: usize) {
+        if let Some(seat) = self.seats.get_mut(seat_index) {
+            seat.phase = SeatPhase::Done;
+        }
+    }
+
+    /// Plays the dealer's hand to completion and resolves every seat against
+    /// it. Call once every seat has finished its turn, whether via
+    /// `play_round`'s automated loop or by driving `hit`/`stand` externally
+    /// one decision at a time.
+    pub fn resolve_round(&mut self) -> (Vec<(String, Outcome)>, RoundLog) {
+        let dealer_upcard = self.dealer_upcard();
+        let mut log = RoundLog::new();
+
+        let dealer_strategy = DealerStrategy;
+        while let Action::Hit = dealer_strategy.decide(&self.dealer_cards, dealer_upcard) {
+            if let Some(card) = self.deck.pop() {
+                self.dealer_cards.push(card);
+                log.record(RoundEvent::DealerDraw { card });
+            } else {
+                break;
+            }
+        }
+
+        let results = self
+            .seats
+            .iter()
+            .map(|seat| {
+                let outcome = resolve_outcome(&seat.cards, &self.dealer_cards);
+
+                log.record(RoundEvent::Outcome {
+                    seat: seat.name.clone(),
+                    outcome,
+                });
+
+                (seat.name.clone(), outcome)
+            })
+            .collect();
+
+        (results, log)
+    }
+
+    /// Plays every seat against the shared dealer hand and returns each
+    /// seat's name paired with its outcome, along with a structured log of
+    /// the deal, every hit, every stand, and the dealer's draws.
+    pub fn play_round(&mut self) -> (Vec<(String, Outcome)>, RoundLog) {
+        let dealer_upcard = self.dealer_upcard();
+        let mut log = RoundLog::new();
+
+        log.record(RoundEvent::InitialDeal {
+            dealer_cards: self.dealer_cards.clone(),
+            seats: self
+                .seats
+                .iter()
+                .map(|seat| (seat.name.clone(), seat.cards.clone()))
+                .collect(),
+        });
+
+        for seat_index in 0..self.seats.len() {
+            while self.seats[seat_index].phase == SeatPhase::Turn {
+                let seat = &self.seats[seat_index];
+                let action = seat.strategy.decide(&seat.cards, dealer_upcard);
+                let seat_name = seat.name.clone();
+
+                match action {
+                    Action::Hit => match self.hit(seat_index) {
+                        Some(card) => log.record(RoundEvent::Hit { seat: seat_name, card }),
+                        None => self.stand(seat_index),
+                    },
+                    Action::Stand => {
+                        self.stand(seat_index);
+                        log.record(RoundEvent::Stand { seat: seat_name });
+                    }
+                }
+            }
+        }
+
+        let (results, resolve_log) = self.resolve_round();
+        log.events.extend(resolve_log.events);
+
+        (results, log)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::card::{Suit, Value};
+
+    struct AlwaysHit;
+
+    impl Strategy for AlwaysHit {
+        fn decide(&self, _own: &[Card], _dealer_upcard: Card) -> Action {
+            Action::Hit
+        }
+    }
+
+    /// A seat that never busts and never stands would spin forever once the
+    /// deck runs out if `play_round` didn't stop drawing on an empty deck.
+    #[test]
+    fn play_round_terminates_when_the_deck_is_exhausted() {
+        let seat = Seat {
+            name: "Greedy".to_string(),
+            strategy: Box::new(AlwaysHit),
+            cards: vec![Card::new(Value::Two, Suit::Spades), Card::new(Value::Three, Suit::Hearts)],
+            phase: SeatPhase::Turn,
+        };
+
+        let mut table = Table {
+            deck: Vec::new(),
+            dealer_cards: vec![Card::new(Value::Ten, Suit::Clubs), Card::new(Value::Seven, Suit::Diamonds)],
+            seats: vec![seat],
+        };
+
+        let (results, _log) = table.play_round();
+        assert_eq!(results.len(), 1);
+    }
+
+    /// A frontend can drive a seat one decision at a time via `hit`/`stand`
+    /// instead of going through a synchronous `Strategy`, then finish the
+    /// round with `resolve_round`.
+    #[test]
+    fn hit_and_stand_drive_a_seat_one_decision_at_a_time() {
+        let seat = Seat::new("You", Box::new(AlwaysHit));
+
+        let mut table = Table {
+            deck: vec![Card::new(Value::Five, Suit::Clubs)],
+            dealer_cards: vec![Card::new(Value::Ten, Suit::Hearts), Card::new(Value::Seven, Suit::Diamonds)],
+            seats: vec![seat],
+        };
+        table.seats[0].cards = vec![Card::new(Value::Nine, Suit::Spades), Card::new(Value::Two, Suit::Hearts)];
+
+        assert_eq!(table.seats[0].phase, SeatPhase::Turn);
+        let drawn = table.hit(0).expect("deck has a card");
+        assert_eq!(drawn, Card::new(Value::Five, Suit::Clubs));
+
+        table.stand(0);
+        assert_eq!(table.seats[0].phase, SeatPhase::Done);
+        assert_eq!(table.hit(0), None);
+
+        let (results, _log) = table.resolve_round();
+        assert_eq!(results.len(), 1);
+    }
+
+    /// More seats than a single deck can cover must error instead of
+    /// underflowing `deck.len() - 2` while dealing.
+    #[test]
+    fn deal_shoe_errors_instead_of_underflowing_when_seats_outnumber_the_shoe() {
+        let seats = (0..30).map(|index| Seat::new(format!("Seat {index}"), Box::new(AlwaysHit))).collect();
+
+        let result = Table::deal_shoe(seats, 1, &mut rand::thread_rng());
+
+        assert_eq!(
+            result.err(),
+            Some(DealError {
+                needed: 62,
+                available: 52,
+            })
+        );
+    }
+}